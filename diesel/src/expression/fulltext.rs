@@ -0,0 +1,78 @@
+use backend::Backend;
+use expression::Expression;
+use query_builder::{AstPass, QueryFragment};
+use result::QueryResult;
+use types::Bool;
+
+/// Marker for backends with indexed full-text search support.
+pub trait SupportsFullTextSearch: Backend {}
+
+#[cfg(feature = "sqlite")]
+impl SupportsFullTextSearch for ::sqlite::Sqlite {}
+
+#[cfg(feature = "postgres")]
+impl SupportsFullTextSearch for ::pg::Pg {}
+
+/// A full-text search predicate: `col MATCH pattern` on SQLite FTS tables,
+/// or `to_tsvector(col) @@ to_tsquery(pattern)` on PostgreSQL. `SqlType` is
+/// always `Bool`, so this composes inside `WHERE`/`HAVING` like any other
+/// predicate.
+#[derive(Debug, Clone, Copy)]
+pub struct Matches<Col, Rhs> {
+    col: Col,
+    pattern: Rhs,
+}
+
+impl<Col, Rhs> Expression for Matches<Col, Rhs>
+where
+    Col: Expression,
+{
+    type SqlType = Bool;
+}
+
+#[cfg(feature = "sqlite")]
+impl<Col, Rhs> QueryFragment<::sqlite::Sqlite> for Matches<Col, Rhs>
+where
+    ::sqlite::Sqlite: SupportsFullTextSearch,
+    Col: QueryFragment<::sqlite::Sqlite>,
+    Rhs: QueryFragment<::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<::sqlite::Sqlite>) -> QueryResult<()> {
+        self.col.walk_ast(out.reborrow())?;
+        out.push_sql(" MATCH ");
+        self.pattern.walk_ast(out.reborrow())
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<Col, Rhs> QueryFragment<::pg::Pg> for Matches<Col, Rhs>
+where
+    ::pg::Pg: SupportsFullTextSearch,
+    Col: QueryFragment<::pg::Pg>,
+    Rhs: QueryFragment<::pg::Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<::pg::Pg>) -> QueryResult<()> {
+        out.push_sql("to_tsvector(");
+        self.col.walk_ast(out.reborrow())?;
+        out.push_sql(") @@ to_tsquery(");
+        self.pattern.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// Adds `.matches(pattern)` to any column-like expression, for indexed
+/// full-text search. Only compiles against a backend with both a `Matches`
+/// `QueryFragment` impl and a `SupportsFullTextSearch` impl (currently
+/// SQLite FTS and PostgreSQL) — `matches` itself is backend-agnostic, so
+/// the gate lives on `Matches`'s `QueryFragment` impls rather than here.
+pub trait MatchesDsl: Expression + Sized {
+    fn matches<Rhs>(self, pattern: Rhs) -> Matches<Self, Rhs> {
+        Matches {
+            col: self,
+            pattern: pattern,
+        }
+    }
+}
+
+impl<Col> MatchesDsl for Col where Col: Expression {}
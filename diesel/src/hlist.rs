@@ -0,0 +1,372 @@
+use std::error::Error;
+
+use backend::Backend;
+use expression::{AppearsOnTable, Expression, SelectableExpression};
+use query_builder::{AstPass, QueryFragment};
+use query_source::Queryable;
+use result::QueryResult;
+use row::Row;
+use types::{FromSqlRow, HasSqlType};
+
+/// The empty heterogeneous list. Implements the row-loading and selection
+/// traits the macro in `types::impls::tuples` implements for tuples
+/// (`HasSqlType`, `FromSqlRow`, `Queryable`, `Expression`, `QueryFragment`,
+/// `AppearsOnTable`, `SelectableExpression`), defined recursively over
+/// `HCons`/`HNil` so there is no arity limit at all: a selection can be
+/// built up programmatically one column at a time by appending to a list
+/// instead of picking a fixed tuple arity ahead of time. The macro's other
+/// tuple traits (`NotNull`, `QueryId`, `NonAggregate`, `InsertValues`,
+/// `AsChangeset`, `Changeset`, `BelongsTo`, `TupleAppend`, the COPY row
+/// traits, and the `Nullable` row impl) are not implemented for
+/// `HCons`/`HNil`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HNil;
+
+/// A non-empty heterogeneous list: `head` followed by the rest of the row
+/// in `tail`, which is itself `HNil` or another `HCons`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HCons<H, T> {
+    pub head: H,
+    pub tail: T,
+}
+
+impl<DB> HasSqlType<HNil> for DB
+where
+    DB: Backend,
+{
+    fn metadata(_: &DB::MetadataLookup) -> DB::TypeMetadata {
+        unreachable!("HNil should never implement `ToSql` directly");
+    }
+
+    fn row_metadata(_: &mut Vec<DB::TypeMetadata>, _: &DB::MetadataLookup) {}
+}
+
+impl<DB> FromSqlRow<HNil, DB> for HNil
+where
+    DB: Backend,
+{
+    fn build_from_row<RowT: Row<DB>>(_: &mut RowT) -> Result<Self, Box<Error + Send + Sync>> {
+        Ok(HNil)
+    }
+
+    fn fields_needed() -> usize {
+        0
+    }
+}
+
+impl<DB> Queryable<HNil, DB> for HNil
+where
+    DB: Backend,
+{
+    type Row = HNil;
+
+    fn build(_: Self::Row) -> Self {
+        HNil
+    }
+}
+
+impl<SH, ST, DB> HasSqlType<HCons<SH, ST>> for DB
+where
+    DB: HasSqlType<SH> + HasSqlType<ST> + Backend,
+{
+    fn metadata(_: &DB::MetadataLookup) -> DB::TypeMetadata {
+        unreachable!("HCons should never implement `ToSql` directly");
+    }
+
+    fn row_metadata(out: &mut Vec<DB::TypeMetadata>, lookup: &DB::MetadataLookup) {
+        <DB as HasSqlType<SH>>::row_metadata(out, lookup);
+        <DB as HasSqlType<ST>>::row_metadata(out, lookup);
+    }
+}
+
+impl<H, T, SH, ST, DB> FromSqlRow<HCons<SH, ST>, DB> for HCons<H, T>
+where
+    DB: Backend,
+    H: FromSqlRow<SH, DB>,
+    T: FromSqlRow<ST, DB>,
+    DB: HasSqlType<SH> + HasSqlType<ST>,
+{
+    fn build_from_row<RowT: Row<DB>>(row: &mut RowT) -> Result<Self, Box<Error + Send + Sync>> {
+        Ok(HCons {
+            head: try!(H::build_from_row(row)),
+            tail: try!(T::build_from_row(row)),
+        })
+    }
+
+    fn fields_needed() -> usize {
+        H::fields_needed() + T::fields_needed()
+    }
+}
+
+impl<H, T, SH, ST, DB> Queryable<HCons<SH, ST>, DB> for HCons<H, T>
+where
+    DB: Backend,
+    H: Queryable<SH, DB>,
+    T: Queryable<ST, DB>,
+    DB: HasSqlType<SH> + HasSqlType<ST>,
+{
+    type Row = HCons<H::Row, T::Row>;
+
+    fn build(row: Self::Row) -> Self {
+        HCons {
+            head: H::build(row.head),
+            tail: T::build(row.tail),
+        }
+    }
+}
+
+impl Expression for HNil {
+    type SqlType = HNil;
+}
+
+impl<H: Expression, T: Expression> Expression for HCons<H, T> {
+    type SqlType = HCons<H::SqlType, T::SqlType>;
+}
+
+impl<DB: Backend> QueryFragment<DB> for HNil {
+    fn walk_ast(&self, _out: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub trait WalkHListTail<DB: Backend> {
+    fn walk_tail(&self, out: AstPass<DB>) -> QueryResult<()>;
+}
+
+impl<DB: Backend> WalkHListTail<DB> for HNil {
+    fn walk_tail(&self, _out: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl<H, T, DB> WalkHListTail<DB> for HCons<H, T>
+where
+    DB: Backend,
+    H: QueryFragment<DB>,
+    T: WalkHListTail<DB>,
+{
+    fn walk_tail(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(", ");
+        self.head.walk_ast(out.reborrow())?;
+        self.tail.walk_tail(out.reborrow())
+    }
+}
+
+impl<H, T, DB> QueryFragment<DB> for HCons<H, T>
+where
+    DB: Backend,
+    H: QueryFragment<DB>,
+    T: WalkHListTail<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.head.walk_ast(out.reborrow())?;
+        self.tail.walk_tail(out.reborrow())
+    }
+}
+
+impl<QS> AppearsOnTable<QS> for HNil {}
+impl<QS> SelectableExpression<QS> for HNil {}
+
+impl<H, T, QS> AppearsOnTable<QS> for HCons<H, T>
+where
+    H: AppearsOnTable<QS>,
+    T: AppearsOnTable<QS>,
+    HCons<H, T>: Expression,
+{
+}
+
+impl<H, T, QS> SelectableExpression<QS> for HCons<H, T>
+where
+    H: SelectableExpression<QS>,
+    T: SelectableExpression<QS>,
+    HCons<H, T>: AppearsOnTable<QS>,
+{
+}
+
+/// Converts between a small flat tuple (one to four elements) and the
+/// equivalent `HList`, so existing tuple-based selections keep working
+/// unchanged while giving users the option to switch to `HList`s to build a
+/// selection programmatically by appending to one. The mapping is
+/// positional: elements are matched by order, not by name.
+impl<A> From<(A,)> for HCons<A, HNil> {
+    fn from((a,): (A,)) -> Self {
+        HCons {
+            head: a,
+            tail: HNil,
+        }
+    }
+}
+
+impl<A> From<HCons<A, HNil>> for (A,) {
+    fn from(list: HCons<A, HNil>) -> Self {
+        (list.head,)
+    }
+}
+
+impl<A, B> From<(A, B)> for HCons<A, HCons<B, HNil>> {
+    fn from((a, b): (A, B)) -> Self {
+        HCons {
+            head: a,
+            tail: HCons {
+                head: b,
+                tail: HNil,
+            },
+        }
+    }
+}
+
+impl<A, B> From<HCons<A, HCons<B, HNil>>> for (A, B) {
+    fn from(list: HCons<A, HCons<B, HNil>>) -> Self {
+        (list.head, list.tail.head)
+    }
+}
+
+impl<A, B, C> From<(A, B, C)> for HCons<A, HCons<B, HCons<C, HNil>>> {
+    fn from((a, b, c): (A, B, C)) -> Self {
+        HCons {
+            head: a,
+            tail: HCons {
+                head: b,
+                tail: HCons {
+                    head: c,
+                    tail: HNil,
+                },
+            },
+        }
+    }
+}
+
+impl<A, B, C> From<HCons<A, HCons<B, HCons<C, HNil>>>> for (A, B, C) {
+    fn from(list: HCons<A, HCons<B, HCons<C, HNil>>>) -> Self {
+        (list.head, list.tail.head, list.tail.tail.head)
+    }
+}
+
+impl<A, B, C, D> From<(A, B, C, D)> for HCons<A, HCons<B, HCons<C, HCons<D, HNil>>>> {
+    fn from((a, b, c, d): (A, B, C, D)) -> Self {
+        HCons {
+            head: a,
+            tail: HCons {
+                head: b,
+                tail: HCons {
+                    head: c,
+                    tail: HCons {
+                        head: d,
+                        tail: HNil,
+                    },
+                },
+            },
+        }
+    }
+}
+
+impl<A, B, C, D> From<HCons<A, HCons<B, HCons<C, HCons<D, HNil>>>>> for (A, B, C, D) {
+    fn from(list: HCons<A, HCons<B, HCons<C, HCons<D, HNil>>>>) -> Self {
+        (
+            list.head,
+            list.tail.head,
+            list.tail.tail.head,
+            list.tail.tail.tail.head,
+        )
+    }
+}
+
+/// Converts a flat tuple into its equivalent `HCons`/`HNil` chain and back.
+pub trait IntoHList {
+    type HList;
+
+    fn into_hlist(self) -> Self::HList;
+}
+
+pub trait FromHList<List> {
+    fn from_hlist(list: List) -> Self;
+}
+
+impl<A> IntoHList for (A,) {
+    type HList = HCons<A, HNil>;
+
+    fn into_hlist(self) -> Self::HList {
+        self.into()
+    }
+}
+
+impl<A> FromHList<HCons<A, HNil>> for (A,) {
+    fn from_hlist(list: HCons<A, HNil>) -> Self {
+        list.into()
+    }
+}
+
+impl<A, B> IntoHList for (A, B) {
+    type HList = HCons<A, HCons<B, HNil>>;
+
+    fn into_hlist(self) -> Self::HList {
+        self.into()
+    }
+}
+
+impl<A, B> FromHList<HCons<A, HCons<B, HNil>>> for (A, B) {
+    fn from_hlist(list: HCons<A, HCons<B, HNil>>) -> Self {
+        list.into()
+    }
+}
+
+impl<A, B, C> IntoHList for (A, B, C) {
+    type HList = HCons<A, HCons<B, HCons<C, HNil>>>;
+
+    fn into_hlist(self) -> Self::HList {
+        self.into()
+    }
+}
+
+impl<A, B, C> FromHList<HCons<A, HCons<B, HCons<C, HNil>>>> for (A, B, C) {
+    fn from_hlist(list: HCons<A, HCons<B, HCons<C, HNil>>>) -> Self {
+        list.into()
+    }
+}
+
+impl<A, B, C, D> IntoHList for (A, B, C, D) {
+    type HList = HCons<A, HCons<B, HCons<C, HCons<D, HNil>>>>;
+
+    fn into_hlist(self) -> Self::HList {
+        self.into()
+    }
+}
+
+impl<A, B, C, D> FromHList<HCons<A, HCons<B, HCons<C, HCons<D, HNil>>>>> for (A, B, C, D) {
+    fn from_hlist(list: HCons<A, HCons<B, HCons<C, HCons<D, HNil>>>>) -> Self {
+        list.into()
+    }
+}
+
+/// Converts a type to and from its fields' `HList` representation, the
+/// struct counterpart to `IntoHList`/`FromHList` for tuples.
+///
+/// `#[derive(Queryable)]` maps a struct to a flat tuple in field order;
+/// this is the equivalent mapping onto an `HList`, which is what lets an
+/// `HList` row deserialize directly into a named struct below instead of
+/// going through an intermediate tuple. `Repr` carries no field names, so
+/// fields are matched by position. There is no derive for this trait: a
+/// type implements `Repr` and the two conversions by hand.
+pub trait LabelledGeneric {
+    type Repr;
+
+    fn into_hlist_repr(self) -> Self::Repr;
+    fn from_hlist_repr(repr: Self::Repr) -> Self;
+}
+
+/// Any type with a `LabelledGeneric` representation deserializes directly
+/// from a row its representation can be built from, without needing its
+/// own hand-written `Queryable` impl.
+impl<T, ST, DB> Queryable<ST, DB> for T
+where
+    DB: Backend + HasSqlType<ST>,
+    T: LabelledGeneric,
+    T::Repr: Queryable<ST, DB>,
+{
+    type Row = <T::Repr as Queryable<ST, DB>>::Row;
+
+    fn build(row: Self::Row) -> Self {
+        T::from_hlist_repr(T::Repr::build(row))
+    }
+}
@@ -0,0 +1,122 @@
+use backend::Backend;
+use expression::Expression;
+use query_builder::{AstPass, QueryFragment};
+use result::QueryResult;
+
+/// The SQL set operator combining two queries together.
+#[derive(Debug, Clone, Copy)]
+pub enum SetOperator {
+    Union,
+    UnionAll,
+    Intersect,
+    Except,
+}
+
+impl SetOperator {
+    fn sql(&self) -> &'static str {
+        match *self {
+            SetOperator::Union => " UNION ",
+            SetOperator::UnionAll => " UNION ALL ",
+            SetOperator::Intersect => " INTERSECT ",
+            SetOperator::Except => " EXCEPT ",
+        }
+    }
+}
+
+/// Marker for backends that support `INTERSECT` and `EXCEPT`. `UNION` and
+/// `UNION ALL` are supported everywhere diesel runs, so those two aren't
+/// gated behind this trait.
+pub trait SupportsIntersectExcept: Backend {}
+
+#[cfg(feature = "sqlite")]
+impl SupportsIntersectExcept for ::sqlite::Sqlite {}
+
+#[cfg(feature = "postgres")]
+impl SupportsIntersectExcept for ::pg::Pg {}
+
+/// The combination of two queries via a SQL set operator (`UNION`,
+/// `UNION ALL`, `INTERSECT`, or `EXCEPT`).
+///
+/// Both sides must share the same `SqlType`, so loading a `Combined` query
+/// reuses the tuple `Queryable`/`FromSqlRow` impls unchanged.
+#[derive(Debug, Clone, Copy)]
+pub struct Combined<Lhs, Rhs> {
+    op: SetOperator,
+    lhs: Lhs,
+    rhs: Rhs,
+}
+
+impl<Lhs, Rhs> Expression for Combined<Lhs, Rhs>
+where
+    Lhs: Expression,
+    Rhs: Expression<SqlType = Lhs::SqlType>,
+{
+    type SqlType = Lhs::SqlType;
+}
+
+impl<Lhs, Rhs, DB> QueryFragment<DB> for Combined<Lhs, Rhs>
+where
+    DB: Backend,
+    Lhs: QueryFragment<DB>,
+    Rhs: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(self.op.sql());
+        self.rhs.walk_ast(out.reborrow())
+    }
+}
+
+/// Combines two select queries with `UNION` or `UNION ALL`, both of which
+/// every backend diesel supports is expected to emit, or with `INTERSECT`/
+/// `EXCEPT` on backends that support it (see `SupportsIntersectExcept`).
+///
+/// `.intersect()`/`.except()` take `DB` as an explicit type parameter rather
+/// than a free function's `where DB: SupportsIntersectExcept`, since `DB`
+/// appears nowhere in their arguments or return type and so can't be
+/// inferred at a call site — callers write `lhs.intersect::<Pg, _>(rhs)`.
+pub trait CombineDsl: Sized {
+    fn union<Rhs>(self, rhs: Rhs) -> Combined<Self, Rhs> {
+        Combined {
+            op: SetOperator::Union,
+            lhs: self,
+            rhs: rhs,
+        }
+    }
+
+    fn union_all<Rhs>(self, rhs: Rhs) -> Combined<Self, Rhs> {
+        Combined {
+            op: SetOperator::UnionAll,
+            lhs: self,
+            rhs: rhs,
+        }
+    }
+
+    fn intersect<DB, Rhs>(self, rhs: Rhs) -> Combined<Self, Rhs>
+    where
+        DB: SupportsIntersectExcept,
+        Self: Expression,
+        Rhs: Expression<SqlType = Self::SqlType>,
+    {
+        Combined {
+            op: SetOperator::Intersect,
+            lhs: self,
+            rhs: rhs,
+        }
+    }
+
+    fn except<DB, Rhs>(self, rhs: Rhs) -> Combined<Self, Rhs>
+    where
+        DB: SupportsIntersectExcept,
+        Self: Expression,
+        Rhs: Expression<SqlType = Self::SqlType>,
+    {
+        Combined {
+            op: SetOperator::Except,
+            lhs: self,
+            rhs: rhs,
+        }
+    }
+}
+
+impl<T> CombineDsl for T {}
@@ -0,0 +1,172 @@
+use backend::Backend;
+use expression::Expression;
+use query_builder::{AstPass, QueryFragment};
+use result::QueryResult;
+use types::Bool;
+
+/// A `GROUP BY` clause over an arbitrary expression.
+///
+/// `expr` may be a single column or a tuple of columns, in which case this
+/// reuses the comma-joining `QueryFragment` tuple impl to render each member
+/// separated by `, `.
+#[derive(Debug, Clone, Copy)]
+pub struct GroupBy<Expr>(Expr);
+
+impl<Expr> GroupBy<Expr> {
+    pub fn new(expr: Expr) -> Self {
+        GroupBy(expr)
+    }
+}
+
+impl<Expr, DB> QueryFragment<DB> for GroupBy<Expr>
+where
+    DB: Backend,
+    Expr: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(" GROUP BY ");
+        self.0.walk_ast(out.reborrow())
+    }
+}
+
+/// A query with a `GROUP BY <expr>` clause appended to `stmt`, rendering the
+/// original query followed by the clause.
+#[derive(Debug, Clone, Copy)]
+pub struct WithGroupBy<Stmt, Expr> {
+    stmt: Stmt,
+    group_by: GroupBy<Expr>,
+}
+
+/// A `GROUP BY` clause doesn't change what a query selects, so the result
+/// keeps `Stmt`'s own `SqlType` — the same way `Combined` forwards
+/// `Lhs::SqlType` — letting `.group_by(...)` reuse the tuple
+/// `Queryable`/`FromSqlRow` machinery unchanged.
+impl<Stmt, Expr> Expression for WithGroupBy<Stmt, Expr>
+where
+    Stmt: Expression,
+{
+    type SqlType = Stmt::SqlType;
+}
+
+impl<Stmt, Expr, DB> QueryFragment<DB> for WithGroupBy<Stmt, Expr>
+where
+    DB: Backend,
+    Stmt: QueryFragment<DB>,
+    Expr: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.stmt.walk_ast(out.reborrow())?;
+        self.group_by.walk_ast(out.reborrow())
+    }
+}
+
+/// Adds a `GROUP BY <expr>` clause to a select statement.
+///
+/// `expr` is anything that implements `Expression`, including a tuple of
+/// aggregate and non-aggregate expressions (e.g. `(count_star(), category)`),
+/// now that the tuple `Expression` impl no longer requires every element to
+/// be `NonAggregate`.
+///
+/// Implemented generically for any query rather than one hardcoded to
+/// `SelectStatement`'s own (already sizable) list of generic parameters, the
+/// same way `.union()`/`.union_all()` are: the `GROUP BY` clause is appended
+/// by wrapping, not by growing the statement type itself.
+pub trait GroupByDsl<Expr: Expression>: Sized {
+    type Output;
+
+    fn group_by(self, expr: Expr) -> Self::Output;
+}
+
+impl<Stmt, Expr> GroupByDsl<Expr> for Stmt
+where
+    Expr: Expression,
+{
+    type Output = WithGroupBy<Stmt, Expr>;
+
+    fn group_by(self, expr: Expr) -> Self::Output {
+        WithGroupBy {
+            stmt: self,
+            group_by: GroupBy::new(expr),
+        }
+    }
+}
+
+/// A `HAVING` clause. Unlike `WHERE`, this is allowed to reference aggregate
+/// expressions, since it filters groups produced by a `GROUP BY` rather than
+/// individual rows.
+#[derive(Debug, Clone, Copy)]
+pub struct Having<Predicate>(Predicate);
+
+impl<Predicate> Having<Predicate>
+where
+    Predicate: Expression<SqlType = Bool>,
+{
+    pub fn new(predicate: Predicate) -> Self {
+        Having(predicate)
+    }
+}
+
+impl<Predicate, DB> QueryFragment<DB> for Having<Predicate>
+where
+    DB: Backend,
+    Predicate: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(" HAVING ");
+        self.0.walk_ast(out.reborrow())
+    }
+}
+
+/// A query with a `HAVING <predicate>` clause appended to `stmt`.
+#[derive(Debug, Clone, Copy)]
+pub struct WithHaving<Stmt, Predicate> {
+    stmt: Stmt,
+    having: Having<Predicate>,
+}
+
+/// A `HAVING` clause doesn't change what a query selects either, so this
+/// forwards `Stmt::SqlType` the same way `WithGroupBy` does.
+impl<Stmt, Predicate> Expression for WithHaving<Stmt, Predicate>
+where
+    Stmt: Expression,
+{
+    type SqlType = Stmt::SqlType;
+}
+
+impl<Stmt, Predicate, DB> QueryFragment<DB> for WithHaving<Stmt, Predicate>
+where
+    DB: Backend,
+    Stmt: QueryFragment<DB>,
+    Predicate: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.stmt.walk_ast(out.reborrow())?;
+        self.having.walk_ast(out.reborrow())
+    }
+}
+
+/// Filters groups produced by a `GROUP BY` clause. The predicate is allowed
+/// to contain aggregate expressions, which `.filter()` (bound to `WHERE`)
+/// does not permit.
+///
+/// Implemented the same way as `GroupByDsl` above: generically, by wrapping
+/// `self` rather than by reaching into a concrete statement type.
+pub trait HavingDsl<Predicate: Expression<SqlType = Bool>>: Sized {
+    type Output;
+
+    fn having(self, predicate: Predicate) -> Self::Output;
+}
+
+impl<Stmt, Predicate> HavingDsl<Predicate> for Stmt
+where
+    Predicate: Expression<SqlType = Bool>,
+{
+    type Output = WithHaving<Stmt, Predicate>;
+
+    fn having(self, predicate: Predicate) -> Self::Output {
+        WithHaving {
+            stmt: self,
+            having: Having::new(predicate),
+        }
+    }
+}
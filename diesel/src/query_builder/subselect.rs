@@ -0,0 +1,100 @@
+use backend::Backend;
+use expression::Expression;
+use query_builder::{AstPass, QueryFragment};
+use result::QueryResult;
+use types::Bool;
+
+/// A subquery used as an expression, e.g. on the right-hand side of `IN`.
+///
+/// `ST` is the SQL row type the subquery returns, which is what lets
+/// `eq_any` enforce at the type level that a two-column tuple can only be
+/// compared against a subquery selecting two columns.
+#[derive(Debug, Clone, Copy)]
+pub struct Subselect<Q, ST> {
+    values: Q,
+    _sql_type: ::std::marker::PhantomData<ST>,
+}
+
+impl<Q, ST> Subselect<Q, ST>
+where
+    Q: Expression<SqlType = ST>,
+{
+    pub fn new(values: Q) -> Self {
+        Subselect {
+            values: values,
+            _sql_type: ::std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Q, ST> Expression for Subselect<Q, ST> {
+    type SqlType = ST;
+}
+
+impl<Q, ST, DB> QueryFragment<DB> for Subselect<Q, ST>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        self.values.walk_ast(out)
+    }
+}
+
+/// A row-value `IN` comparison: `(lhs) IN (subquery)`.
+///
+/// `Lhs` is typically a tuple expression, reusing the comma-joining
+/// `QueryFragment` tuple impl for the left-hand side; for a one-element
+/// tuple this still emits a single pair of parens rather than a degenerate
+/// extra level, since `Lhs`'s own `QueryFragment` impl never adds parens of
+/// its own.
+#[derive(Debug, Clone, Copy)]
+pub struct InSubselect<Lhs, Subquery> {
+    lhs: Lhs,
+    subquery: Subquery,
+}
+
+impl<Lhs, Subquery> Expression for InSubselect<Lhs, Subquery>
+where
+    Lhs: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<Lhs, Subquery, DB> QueryFragment<DB> for InSubselect<Lhs, Subquery>
+where
+    DB: Backend,
+    Lhs: QueryFragment<DB>,
+    Subquery: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(") IN (");
+        self.subquery.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// Row-value `IN` subquery comparisons, e.g.
+/// `(users::id, users::org_id).eq_any(some_boxed_select)`.
+pub trait EqAny<Rhs> {
+    type Output;
+
+    fn eq_any(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<Lhs, Q, ST> EqAny<Subselect<Q, ST>> for Lhs
+where
+    Lhs: Expression<SqlType = ST>,
+{
+    type Output = InSubselect<Lhs, Subselect<Q, ST>>;
+
+    fn eq_any(self, rhs: Subselect<Q, ST>) -> Self::Output {
+        InSubselect {
+            lhs: self,
+            subquery: rhs,
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use backend::Backend;
+use expression::{AppearsOnTable, Expression, SelectableExpression};
+use query_builder::{AstPass, QueryFragment};
+use query_source::QuerySource;
+use result::QueryResult;
+
+/// A marker type naming one common table expression, usable as the pseudo
+/// `QuerySource` a selection against the CTE is written in terms of — the
+/// same role a table's marker type plays for an ordinary `FROM` source.
+///
+/// `Name` is a zero-sized marker unique to one CTE (e.g. a caller-defined
+/// `struct RecentOrders;`), so two CTEs never unify just because they
+/// happen to share a runtime name string.
+#[derive(Debug, Clone, Copy)]
+pub struct CteRef<Name> {
+    name: &'static str,
+    _marker: ::std::marker::PhantomData<Name>,
+}
+
+impl<Name> CteRef<Name> {
+    pub fn new(name: &'static str) -> Self {
+        CteRef {
+            name: name,
+            _marker: ::std::marker::PhantomData,
+        }
+    }
+
+    /// Accesses one of the CTE's columns by name, so it can appear in a
+    /// selection against it — the `CteRef` counterpart to
+    /// `query_source::alias::Alias::field`.
+    ///
+    /// `ST` is the SQL type of the column, asserted by the caller the same
+    /// way `table!`-defined column types assert theirs: there is no backing
+    /// `Table` here to check it against, since a CTE's column list is a
+    /// runtime `&'static [&'static str]` (see `CommonTableExpr`) rather than
+    /// a set of generated column types.
+    pub fn column<ST>(&self, name: &'static str) -> CteColumn<Name, ST> {
+        CteColumn {
+            cte_name: self.name,
+            column_name: name,
+            _marker: ::std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Name> QuerySource for CteRef<Name> {
+    type FromClause = Self;
+
+    fn from_clause(&self) -> Self::FromClause {
+        CteRef::new(self.name)
+    }
+}
+
+impl<Name, DB> QueryFragment<DB> for CteRef<Name>
+where
+    DB: Backend,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_identifier(self.name)
+    }
+}
+
+/// A column of a common table expression, rendering as
+/// `cte_name.column_name`, the `CteRef` counterpart to
+/// `query_source::alias::AliasedColumn`.
+#[derive(Debug, Clone, Copy)]
+pub struct CteColumn<Name, ST> {
+    cte_name: &'static str,
+    column_name: &'static str,
+    _marker: ::std::marker::PhantomData<(Name, ST)>,
+}
+
+impl<Name, ST> Expression for CteColumn<Name, ST> {
+    type SqlType = ST;
+}
+
+impl<Name, ST, DB> QueryFragment<DB> for CteColumn<Name, ST>
+where
+    DB: Backend,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_identifier(self.cte_name)?;
+        out.push_sql(".");
+        out.push_identifier(self.column_name)?;
+        Ok(())
+    }
+}
+
+impl<Name, ST> SelectableExpression<CteRef<Name>> for CteColumn<Name, ST> where
+    CteColumn<Name, ST>: AppearsOnTable<CteRef<Name>>
+{
+}
+
+impl<Name, ST> AppearsOnTable<CteRef<Name>> for CteColumn<Name, ST> where
+    CteColumn<Name, ST>: Expression
+{
+}
+
+/// A single common table expression: `name(col1, col2, ...) AS (body)`.
+///
+/// `body` may itself be a `Combined` set-operation query (see
+/// `query_builder::combine::CombineDsl::union_all`), which is how
+/// `WITH RECURSIVE name AS (base UNION ALL recursive_step)` is built.
+#[derive(Debug, Clone, Copy)]
+pub struct CommonTableExpr<Name, Body> {
+    name: CteRef<Name>,
+    columns: &'static [&'static str],
+    body: Body,
+}
+
+impl<Name, Body> CommonTableExpr<Name, Body> {
+    pub fn new(name: CteRef<Name>, columns: &'static [&'static str], body: Body) -> Self {
+        CommonTableExpr {
+            name: name,
+            columns: columns,
+            body: body,
+        }
+    }
+}
+
+impl<Name, Body, DB> QueryFragment<DB> for CommonTableExpr<Name, Body>
+where
+    DB: Backend,
+    Body: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.name.walk_ast(out.reborrow())?;
+        out.push_sql("(");
+        for (i, column) in self.columns.iter().enumerate() {
+            if i != 0 {
+                out.push_sql(", ");
+            }
+            out.push_identifier(column)?;
+        }
+        out.push_sql(") AS (");
+        self.body.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// Wraps `body` with one or more common table expressions in scope, emitting
+/// `WITH <ctes> <body>` (or `WITH RECURSIVE` when `.recursive()` was called).
+///
+/// `Ctes` is typically a tuple of `CommonTableExpr`s, reusing the
+/// comma-joining `QueryFragment` tuple impl. Each CTE's name is a real
+/// `QuerySource` (see `CteRef`), and its columns are accessed through
+/// `CteRef::column` (see `CteColumn`), so the tuple
+/// `SelectableExpression`/`AppearsOnTable` impls apply to a tuple of
+/// `CteColumn`s the same way they do for a tuple of ordinary table columns.
+#[derive(Debug, Clone, Copy)]
+pub struct With<Ctes, Body> {
+    ctes: Ctes,
+    recursive: bool,
+    body: Body,
+}
+
+impl<Ctes, Body> With<Ctes, Body> {
+    pub fn new(ctes: Ctes, body: Body) -> Self {
+        With {
+            ctes: ctes,
+            recursive: false,
+            body: body,
+        }
+    }
+
+    /// Emits `WITH RECURSIVE` instead of `WITH`.
+    pub fn recursive(mut self) -> Self {
+        self.recursive = true;
+        self
+    }
+}
+
+impl<Ctes, Body, DB> QueryFragment<DB> for With<Ctes, Body>
+where
+    DB: Backend,
+    Ctes: QueryFragment<DB>,
+    Body: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(if self.recursive {
+            "WITH RECURSIVE "
+        } else {
+            "WITH "
+        });
+        self.ctes.walk_ast(out.reborrow())?;
+        out.push_sql(" ");
+        self.body.walk_ast(out.reborrow())
+    }
+}
+
+/// Introduces one or more common table expressions (`ctes`) in scope for
+/// `body`. See `With::recursive` for `WITH RECURSIVE` support.
+pub fn with<Ctes, Body>(ctes: Ctes, body: Body) -> With<Ctes, Body> {
+    With::new(ctes, body)
+}
@@ -0,0 +1,108 @@
+use backend::Backend;
+use expression::{AppearsOnTable, Expression, SelectableExpression};
+use query_builder::{AstPass, QueryFragment};
+use query_source::{Column, QuerySource, Table};
+use result::QueryResult;
+
+/// A distinct name for a second (or further) instance of `table` in a
+/// query, enabling self-joins: selecting from two different `Alias`es of
+/// the same `Table` type-checks, and renders as `FROM table AS alias`.
+#[derive(Debug, Clone, Copy)]
+pub struct Alias<T> {
+    name: &'static str,
+    table: T,
+}
+
+impl<T: Table> Alias<T> {
+    pub fn new(table: T, name: &'static str) -> Self {
+        Alias {
+            name: name,
+            table: table,
+        }
+    }
+
+    /// Re-exposes one of the aliased table's columns, so `alias.field(col)`
+    /// yields a column whose `walk_ast` emits `alias_name.field` rather than
+    /// the table's own name, keeping joins against two aliases of the same
+    /// table unambiguous.
+    pub fn field<C>(&self, _column: C) -> AliasedColumn<T, C>
+    where
+        C: Column<Table = T>,
+    {
+        AliasedColumn {
+            alias_name: self.name,
+            _table: ::std::marker::PhantomData,
+            _column: ::std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, DB> QueryFragment<DB> for Alias<T>
+where
+    DB: Backend,
+    T: Table + QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.table.walk_ast(out.reborrow())?;
+        out.push_sql(" AS ");
+        out.push_identifier(self.name)?;
+        Ok(())
+    }
+}
+
+/// Lets `Alias<T>` be placed directly in a `FROM`/join, the same as `T`
+/// itself — without this, a query could render an aliased table but could
+/// never actually select from it.
+impl<T: Table + Copy> QuerySource for Alias<T> {
+    type FromClause = Self;
+
+    fn from_clause(&self) -> Self::FromClause {
+        *self
+    }
+}
+
+/// A column of `T` accessed through an `Alias`, rendering as
+/// `alias_name.column_name` so join `ON` predicates resolve against the
+/// in-scope alias instead of the table's original name.
+#[derive(Debug, Clone, Copy)]
+pub struct AliasedColumn<T, C> {
+    alias_name: &'static str,
+    _table: ::std::marker::PhantomData<T>,
+    _column: ::std::marker::PhantomData<C>,
+}
+
+impl<T, C> Expression for AliasedColumn<T, C>
+where
+    C: Expression,
+{
+    type SqlType = C::SqlType;
+}
+
+impl<T, C, DB> QueryFragment<DB> for AliasedColumn<T, C>
+where
+    DB: Backend,
+    C: Column,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_identifier(self.alias_name)?;
+        out.push_sql(".");
+        out.push_identifier(C::name())?;
+        Ok(())
+    }
+}
+
+impl<T, C> SelectableExpression<Alias<T>> for AliasedColumn<T, C>
+where
+    T: Table,
+    C: Column<Table = T>,
+    AliasedColumn<T, C>: AppearsOnTable<Alias<T>>,
+{
+}
+
+impl<T, C> AppearsOnTable<Alias<T>> for AliasedColumn<T, C>
+where
+    T: Table,
+    C: Column<Table = T>,
+    AliasedColumn<T, C>: Expression,
+{
+}
@@ -1,9 +1,13 @@
 use std::error::Error;
+#[cfg(feature = "postgres")]
+use std::io::{self, Write};
 
 use associations::BelongsTo;
 use backend::{Backend, SupportsDefaultKeyword};
 use expression::{Expression, SelectableExpression, AppearsOnTable, NonAggregate};
 use insertable::{ColumnInsertValue, InsertValues};
+#[cfg(feature = "postgres")]
+use pg::copy::{CopyToRow, ToBinaryCopyRow};
 use query_builder::*;
 use query_source::{QuerySource, Queryable, Table, Column};
 use result::QueryResult;
@@ -11,6 +15,63 @@ use row::Row;
 use types::{HasSqlType, FromSqlRow, Nullable, NotNull};
 use util::TupleAppend;
 
+/// A friendlier compile error for a tuple/row type mismatch.
+///
+/// Without this, selecting into a tuple whose element doesn't line up with
+/// its SQL type produces a wall of text naming every tuple arity's
+/// `FromSqlRow`/`Queryable` impl, since rustc has no way to know which
+/// element of which arity the caller actually meant. Each arity's
+/// `FromSqlRow` impl below carries one `TupleElement<ST, DB, INDEX>` bound
+/// per element, and its `SelectableExpression` impl one
+/// `SelectableTupleElement<QS, INDEX>` bound per element, *instead of* a raw
+/// `FromSqlRow`/`SelectableExpression` bound, so a mismatch reports only
+/// the concrete tuple element and SQL type that failed, with a note
+/// pointing at a likely column/type swap, rather than that plus the
+/// original wall of text. `FromSqlRow` is a supertrait of `TupleElement`
+/// (and `SelectableExpression` of `SelectableTupleElement`), so the macro
+/// still gets to call `build_from_row`/treat the element as selectable
+/// through them. `Queryable` mismatches surface the same way,
+/// since `Queryable::Row` bottoms out in a `FromSqlRow` impl that carries
+/// this bound.
+#[cfg_attr(
+    feature = "unstable",
+    diagnostic::on_unimplemented(
+        message = "element {INDEX} of this tuple does not implement `FromSql` for SQL type `{ST}`",
+        note = "expected a Rust type implementing `FromSql<{ST}, {DB}>` at this position; check for a column/type swap"
+    )
+)]
+pub trait TupleElement<ST, DB, const INDEX: usize>: FromSqlRow<ST, DB> {}
+
+impl<T, ST, DB, const INDEX: usize> TupleElement<ST, DB, INDEX> for T where
+    T: FromSqlRow<ST, DB>
+{
+}
+
+/// A friendlier compile error for a tuple/row `SelectableExpression`
+/// mismatch, the `SelectableExpression` counterpart to `TupleElement`
+/// above.
+#[cfg_attr(
+    feature = "unstable",
+    diagnostic::on_unimplemented(
+        message = "element {INDEX} of this tuple is not selectable from `{QS}`",
+        note = "expected a column (or other `SelectableExpression`) belonging to `{QS}` at this position; check for a table/tuple-position mismatch"
+    )
+)]
+pub trait SelectableTupleElement<QS, const INDEX: usize>: SelectableExpression<QS> {}
+
+impl<T, QS, const INDEX: usize> SelectableTupleElement<QS, INDEX> for T where
+    T: SelectableExpression<QS>
+{
+}
+
+// Not yet implemented: collapsing this macro's hand-expanded per-arity
+// impls into a single recursive implementation over `hlist::HCons`/`HNil`,
+// to cut the compile-time/monomorphization cost of generating `FromSqlRow`,
+// `QueryFragment`, and `SelectableExpression` for every tuple arity up
+// front. `hlist` (see `::hlist`) gives callers an arity-free row type to opt
+// into today, but it's an additive alternative alongside this macro, not a
+// replacement for it — every arity below is still its own hand-written impl
+// block.
 macro_rules! tuple_impls {
     ($(
         $Tuple:tt {
@@ -36,7 +97,7 @@ macro_rules! tuple_impls {
 
             impl<$($T),+, $($ST),+, DB> FromSqlRow<($($ST,)+), DB> for ($($T,)+) where
                 DB: Backend,
-                $($T: FromSqlRow<$ST, DB>),+,
+                $($T: TupleElement<$ST, DB, $idx>),+,
                 $(DB: HasSqlType<$ST>),+,
                 DB: HasSqlType<($($ST,)+)>,
             {
@@ -83,7 +144,7 @@ macro_rules! tuple_impls {
                 }
             }
 
-            impl<$($T: Expression + NonAggregate),+> Expression for ($($T,)+) {
+            impl<$($T: Expression),+> Expression for ($($T,)+) {
                 type SqlType = ($(<$T as Expression>::SqlType,)+);
             }
 
@@ -186,7 +247,7 @@ macro_rules! tuple_impls {
             }
 
             impl<$($T,)+ QS> SelectableExpression<QS> for ($($T,)+) where
-                $($T: SelectableExpression<QS>,)+
+                $($T: SelectableTupleElement<QS, $idx>,)+
                 ($($T,)+): AppearsOnTable<QS>,
             {
             }
@@ -249,6 +310,32 @@ macro_rules! tuple_impls {
                 }
             }
 
+            #[cfg(feature = "postgres")]
+            impl<$($T,)+ $($ST,)+> CopyToRow<($($ST,)+)> for ($($T,)+) where
+                $($T: ::pg::copy::ToCsvField<$ST>,)+
+            {
+                fn write_csv_row<W: Write>(&self, out: &mut W, delimiter: u8, null_token: &str) -> io::Result<()> {
+                    $(
+                        if $idx != 0 {
+                            out.write_all(&[delimiter])?;
+                        }
+                        self.$idx.write_csv_field(out, delimiter, null_token)?;
+                    )+
+                    out.write_all(b"\n")
+                }
+            }
+
+            #[cfg(feature = "postgres")]
+            impl<$($T,)+ $($ST,)+> ToBinaryCopyRow<($($ST,)+)> for ($($T,)+) where
+                $($T: ::pg::copy::ToBinaryCopyField<$ST>,)+
+            {
+                fn write_binary_row<W: Write>(&self, out: &mut W) -> io::Result<()> {
+                    out.write_all(&($Tuple as i16).to_be_bytes())?;
+                    $(self.$idx.write_binary_field(out)?;)+
+                    Ok(())
+                }
+            }
+
             impl<$($T,)+ Next> TupleAppend<Next> for ($($T,)+) {
                 type Output = ($($T,)+ Next);
 
@@ -433,7 +520,15 @@ tuple_impls! {
     }
 }
 
-#[cfg(feature = "large-tables")]
+/// Tiered by Cargo feature to cut the compile-time and monomorphization tax
+/// this macro imposes on the rest of the ecosystem: without these features
+/// only arities 1 through 16 in the block above are compiled, and crates
+/// that select wider rows opt into more headroom with `tuple-impls-32`
+/// (this block, arities 17 through 32) or `tuple-impls-64` (arities 33
+/// through 52, below). There is no `tuple-impls-128` tier, so 52 is the
+/// widest flat tuple. The macro body itself is unchanged; only this driver
+/// is partitioned.
+#[cfg(feature = "tuple-impls-32")]
 tuple_impls! {
     17 {
         (0) -> A, SA, TA,
@@ -670,10 +765,6 @@ tuple_impls! {
         (24) -> Y, SY, TY,
         (25) -> Z, SZ, TZ,
     }
-}
-
-#[cfg(feature = "huge-tables")]
-tuple_impls! {
     27 {
         (0) -> A, SA, TA,
         (1) -> B, SB, TB,
@@ -863,6 +954,11 @@ tuple_impls! {
         (30) -> AE, SAE, TAE,
         (31) -> AF, SAF, TAF,
     }
+}
+
+/// Opt-in tier covering arities 33 through 52.
+#[cfg(feature = "tuple-impls-64")]
+tuple_impls! {
     33 {
         (0) -> A, SA, TA,
         (1) -> B, SB, TB,
@@ -1754,3 +1850,112 @@ tuple_impls! {
         (51) -> AZ, SAZ, TAZ,
     }
 }
+
+// `tuple-impls-128` (arities 53 through 128) is reserved for a follow-up
+// once there's a concrete need for rows that wide; add it the same way as
+// the tier above, gated on `#[cfg(feature = "tuple-impls-128")]`.
+
+// A right-nested "cons" pair such as `(a, (b, (c, ())))` already composes
+// with every impl the macro above generates for the flat 2-tuple case:
+// `Tail` unifies with whatever the next element is, including another
+// nested pair, so `Queryable`, `FromSqlRow`, `Expression`,
+// `SelectableExpression`, and friends all recurse for free today. `()`
+// terminates the chain; give it the trivial zero-column impls so a nested
+// selection doesn't need a flat tuple as its final element.
+impl<DB> HasSqlType<()> for DB
+where
+    DB: Backend,
+{
+    fn metadata(_: &DB::MetadataLookup) -> DB::TypeMetadata {
+        unreachable!("() should never implement `ToSql` directly");
+    }
+
+    fn row_metadata(_: &mut Vec<DB::TypeMetadata>, _: &DB::MetadataLookup) {}
+}
+
+impl<DB> FromSqlRow<(), DB> for ()
+where
+    DB: Backend,
+{
+    fn build_from_row<RowT: Row<DB>>(_: &mut RowT) -> Result<Self, Box<Error + Send + Sync>> {
+        Ok(())
+    }
+
+    fn fields_needed() -> usize {
+        0
+    }
+}
+
+impl<DB> Queryable<(), DB> for ()
+where
+    DB: Backend,
+{
+    type Row = ();
+
+    fn build(_: Self::Row) -> Self {}
+}
+
+/// Converts a right-nested cons tuple like `(a, (b, (c, ())))`, built solely
+/// to select more columns than the largest generated tuple arity, back into
+/// an ordinary flat tuple `(a, b, c)` — which can then deserialize into a
+/// user struct the same way any other flat tuple selection would.
+///
+/// Field order follows the depth-first left-to-right traversal of the
+/// nesting, which matches SQL column order.
+pub trait Flatten {
+    type Flat;
+
+    fn flatten(self) -> Self::Flat;
+}
+
+impl<T> Flatten for T
+where
+    T: FlattenInto<()>,
+{
+    type Flat = <T as FlattenInto<()>>::Output;
+
+    fn flatten(self) -> Self::Flat {
+        self.flatten_into(())
+    }
+}
+
+#[doc(hidden)]
+pub trait FlattenInto<Acc> {
+    type Output;
+
+    fn flatten_into(self, acc: Acc) -> Self::Output;
+}
+
+// The macro above only generates `TupleAppend` for arities 1 and up, since an
+// arity-0 tuple isn't one of its generated impls. `flatten`'s accumulator
+// starts from `()`, so without this base case `flatten_into` can't even
+// append the first element of a tuple being flattened.
+impl<Next> TupleAppend<Next> for () {
+    type Output = (Next,);
+
+    fn tuple_append(self, next: Next) -> Self::Output {
+        (next,)
+    }
+}
+
+impl<Acc> FlattenInto<Acc> for () {
+    type Output = Acc;
+
+    fn flatten_into(self, acc: Acc) -> Self::Output {
+        acc
+    }
+}
+
+impl<Head, Tail, Acc> FlattenInto<Acc> for (Head, Tail)
+where
+    Acc: TupleAppend<Head>,
+    Tail: FlattenInto<<Acc as TupleAppend<Head>>::Output>,
+{
+    type Output = <Tail as FlattenInto<<Acc as TupleAppend<Head>>::Output>>::Output;
+
+    fn flatten_into(self, acc: Acc) -> Self::Output {
+        let (head, tail) = self;
+        tail.flatten_into(acc.tuple_append(head))
+    }
+}
+